@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rules::RuleStatus;
+
+// -------------------------------------------------------------------------------------------------
+// Rule
+// -------------------------------------------------------------------------------------------------
+/// A single detection rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rule {
+    /// The human-readable name of the rule.
+    pub name: String,
+
+    /// The globally-unique identifier of the rule.
+    pub id: String,
+
+    /// The regex pattern that the rule matches.
+    pub pattern: String,
+
+    /// Reference links with more information about this rule.
+    #[serde(default)]
+    pub references: Vec<String>,
+
+    /// The lifecycle status of the rule.
+    #[serde(default)]
+    pub status: RuleStatus,
+}
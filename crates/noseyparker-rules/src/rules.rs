@@ -1,27 +1,323 @@
 use anyhow::{bail, Context, Result};
+use ignore::overrides::OverrideBuilder;
 use ignore::types::TypesBuilder;
 use ignore::WalkBuilder;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 use tracing::{debug, debug_span};
 
 use crate::{util, Rule};
 
+/// The `ignore` file-type names selected when walking a rules directory.
+const RULE_FILE_TYPES: &[&str] = &["yaml", "json", "toml", "markdown"];
+
+/// Deserialize the rules contained in `contents`, dispatching on the extension
+/// of `path`.
+fn deserialize_rules(path: &Path, contents: &[u8]) -> Result<Vec<Rule>> {
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    let rules = match ext.as_str() {
+        "yaml" | "yml" => {
+            let rs: Rules = serde_yaml::from_slice(contents)
+                .with_context(|| format!("Failed to load rules YAML from {}", path.display()))?;
+            rs.rules
+        }
+        "json" => {
+            let rs: Rules = serde_json::from_slice(contents)
+                .with_context(|| format!("Failed to load rules JSON from {}", path.display()))?;
+            rs.rules
+        }
+        "toml" => {
+            let text = std::str::from_utf8(contents)
+                .with_context(|| format!("Failed to load rules TOML from {}", path.display()))?;
+            let rs: Rules = toml::from_str(text)
+                .with_context(|| format!("Failed to load rules TOML from {}", path.display()))?;
+            rs.rules
+        }
+        "md" | "markdown" => deserialize_markdown_rule(path, contents)?,
+        other => bail!("Unhandled rule file extension {:?} for {}", other, path.display()),
+    };
+
+    Ok(rules)
+}
+
+/// Parse a markdown rule file: a single rule defined in a leading `---`-delimited
+/// YAML frontmatter block, with the trailing markdown kept as documentation.
+fn deserialize_markdown_rule(path: &Path, contents: &[u8]) -> Result<Vec<Rule>> {
+    let text = std::str::from_utf8(contents)
+        .with_context(|| format!("Failed to load markdown rule from {}", path.display()))?;
+    let after_open = text.strip_prefix("---").with_context(|| {
+        format!("{}: markdown rule file must begin with a `---` frontmatter block", path.display())
+    })?;
+    let (frontmatter, body) = split_frontmatter(after_open)
+        .with_context(|| format!("{}: unterminated `---` frontmatter block", path.display()))?;
+
+    let mut rule: Rule = serde_yaml::from_str(frontmatter)
+        .with_context(|| format!("Failed to parse frontmatter of {}", path.display()))?;
+
+    let body = body.trim();
+    if !body.is_empty() {
+        rule.references.push(body.to_string());
+    }
+
+    Ok(vec![rule])
+}
+
+/// Parse each file in `paths` concurrently, with at most `budget` files open at
+/// once, returning one [`Rules`] per input in the same order as `paths`.
+///
+/// The worker-pool size is the open-fd permit count: a worker parses exactly
+/// one file at a time, so the number of workers bounds the open descriptors and
+/// keeps loading from ever tripping EMFILE.
+fn parse_files_bounded(paths: &[PathBuf], budget: usize) -> Result<Vec<Rules>> {
+    if paths.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let workers = budget.min(paths.len());
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<Result<Rules>>>> =
+        (0..paths.len()).map(|_| Mutex::new(None)).collect();
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            let next = &next;
+            let results = &results;
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= paths.len() {
+                    break;
+                }
+                *results[i].lock().unwrap() = Some(Rules::from_file(&paths[i]));
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|slot| slot.into_inner().unwrap().expect("every file is assigned to a worker"))
+        .collect()
+}
+
+/// The default cap on concurrently-open rule files: a fraction of the process's
+/// soft open-file limit, with a conservative fallback when it cannot be read.
+fn default_open_file_budget() -> usize {
+    const FALLBACK: usize = 128;
+    const FRACTION: usize = 4;
+    (read_soft_nofile_limit().unwrap_or(FALLBACK) / FRACTION).max(1)
+}
+
+/// Read the soft open-file (`RLIMIT_NOFILE`) limit from `/proc/self/limits`, if
+/// available.
+fn read_soft_nofile_limit() -> Option<usize> {
+    let limits = std::fs::read_to_string("/proc/self/limits").ok()?;
+    for line in limits.lines() {
+        if let Some(rest) = line.strip_prefix("Max open files") {
+            return rest.split_whitespace().next().and_then(|v| v.parse().ok());
+        }
+    }
+    None
+}
+
+/// Does `path` name a rule file in one of the recognized formats?
+fn is_rule_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase()).as_deref(),
+        Some("yaml" | "yml" | "json" | "toml" | "md" | "markdown")
+    )
+}
+
+/// The supported compressed archive formats for bundled rulesets.
+enum ArchiveKind {
+    TarGz,
+    Zip,
+}
+
+/// Classify `path` as a supported archive by extension, if applicable.
+fn archive_kind(path: &Path) -> Option<ArchiveKind> {
+    let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+    if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        Some(ArchiveKind::TarGz)
+    } else if name.ends_with(".zip") {
+        Some(ArchiveKind::Zip)
+    } else {
+        None
+    }
+}
+
+/// Read the rule files from a gzip-compressed tar archive, sorted by path for
+/// stable ordinals.
+fn read_tar_gz<R: std::io::Read>(reader: R) -> Result<Vec<(PathBuf, Vec<u8>)>> {
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(reader));
+    let mut entries = Vec::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path()?.into_owned();
+        if !is_rule_file(&path) {
+            continue;
+        }
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes)?;
+        entries.push((path, bytes));
+    }
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(entries)
+}
+
+/// Read the rule files from a zip archive, sorted by path for stable ordinals.
+fn read_zip<R: std::io::Read + std::io::Seek>(reader: R) -> Result<Vec<(PathBuf, Vec<u8>)>> {
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let mut entries = Vec::new();
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        if !entry.is_file() {
+            continue;
+        }
+        let path = PathBuf::from(entry.name());
+        if !is_rule_file(&path) {
+            continue;
+        }
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes)?;
+        entries.push((path, bytes));
+    }
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(entries)
+}
+
+/// Split the text following the opening `---` of a frontmatter block into the
+/// frontmatter region and the trailing body, at the next line consisting solely
+/// of `---`.
+fn split_frontmatter(s: &str) -> Option<(&str, &str)> {
+    let mut offset = 0;
+    for line in s.split_inclusive('\n') {
+        if offset > 0 && line.trim_end_matches(['\r', '\n']).trim() == "---" {
+            return Some((&s[..offset], &s[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+// -------------------------------------------------------------------------------------------------
+// RuleStatus
+// -------------------------------------------------------------------------------------------------
+/// The lifecycle status of a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleStatus {
+    /// A production rule, loaded by default.
+    #[default]
+    Stable,
+    /// A rule under evaluation; dropped by [`Rules::from_paths`] and
+    /// [`Rules::from_directory`] unless `include_experimental` is set.
+    /// [`Rules::from_file`] and [`Rules::from_archive`] keep it.
+    Experimental,
+    /// A retired rule; dropped by [`Rules::from_paths`] and
+    /// [`Rules::from_directory`] unless `include_deprecated` is set.
+    /// [`Rules::from_file`] and [`Rules::from_archive`] keep it.
+    Deprecated,
+}
+
+// -------------------------------------------------------------------------------------------------
+// LoadOptions
+// -------------------------------------------------------------------------------------------------
+/// Options controlling how rules are loaded.
+#[derive(Debug, Clone)]
+pub struct LoadOptions {
+    /// Load rules marked `deprecated` (dropped by default).
+    pub include_deprecated: bool,
+
+    /// Load rules marked `experimental` (dropped by default).
+    pub include_experimental: bool,
+
+    /// Cap on concurrently-open rule files when loading a directory; `None`
+    /// uses a fraction of the process's open-descriptor limit.
+    pub max_open_files: Option<usize>,
+
+    /// Glob patterns selecting which rule files within a directory to load; an
+    /// empty list loads everything not excluded.
+    pub includes: Vec<String>,
+
+    /// Glob patterns excluding rule files within a directory from loading.
+    pub excludes: Vec<String>,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        LoadOptions {
+            include_deprecated: false,
+            include_experimental: false,
+            max_open_files: None,
+            includes: Vec::new(),
+            excludes: Vec::new(),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// RuleSource
+// -------------------------------------------------------------------------------------------------
+/// The originating path of a rule and its 0-based ordinal within that file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleSource {
+    /// The path of the file this rule was loaded from.
+    pub path: PathBuf,
+
+    /// The 0-based index of this rule within its source file.
+    pub ordinal: usize,
+}
+
+impl RuleSource {
+    fn new<P: Into<PathBuf>>(path: P, ordinal: usize) -> Self {
+        RuleSource { path: path.into(), ordinal }
+    }
+
+    /// A source for a rule constructed in memory rather than loaded from a file.
+    pub fn synthetic() -> Self {
+        RuleSource { path: PathBuf::new(), ordinal: 0 }
+    }
+}
+
+impl std::fmt::Display for RuleSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]", self.path.display(), self.ordinal)
+    }
+}
+
 // -------------------------------------------------------------------------------------------------
 // Rules
 // -------------------------------------------------------------------------------------------------
 #[derive(Serialize, Deserialize)]
 pub struct Rules {
-    pub rules: Vec<Rule>,
+    /// Private so the invariant `rules.len() == sources.len()` can only be
+    /// maintained from within this module; read via [`Rules::rules`].
+    rules: Vec<Rule>,
+
+    /// Provenance for each rule, parallel to `rules`. Not part of the
+    /// serialized representation: it is reconstructed at load time.
+    #[serde(skip)]
+    sources: Vec<RuleSource>,
 }
 
 impl Rules {
     pub fn from_paths_and_contents<'a, I: IntoIterator<Item=(&'a Path, &'a [u8])>>(iterable: I) -> Result<Self> {
-        let mut rules = Rules { rules: Vec::new() };
+        let mut rules = Rules::new();
         for (path, contents) in iterable.into_iter() {
-            let rs: Self = serde_yaml::from_reader(contents)
-                .with_context(|| format!("Failed to load rules YAML from {}", path.display()))?;
-            rules.extend(rs);
+            let loaded = deserialize_rules(path, contents)?;
+            rules.extend_from_path(path, loaded);
         }
 
         Ok(rules)
@@ -29,24 +325,53 @@ impl Rules {
 
     /// Create an empty collection of rules.
     pub fn new() -> Self {
-        Rules { rules: Vec::new() }
+        Rules { rules: Vec::new(), sources: Vec::new() }
+    }
+
+    /// Load rules from the given paths and run [`Rules::validate`] before
+    /// returning, failing if any problem is detected.
+    ///
+    /// This is the "lint my ruleset" step: it surfaces a broken regex or a
+    /// duplicate id up front rather than discovering it mid-scan.
+    pub fn from_paths_validated<P: AsRef<Path>, I: IntoIterator<Item=P>>(paths: I) -> Result<Self> {
+        let rules = Rules::from_paths(paths)?;
+        let report = rules.validate()?;
+        if report.has_problems() {
+            bail!("Rule validation failed:\n{report}");
+        }
+        Ok(rules)
     }
 
-    /// Load rules from the given paths, which may refer either to YAML files or to directories.
+    /// Load rules from the given paths, which may refer to rule files,
+    /// directories, or archives, using the default [`LoadOptions`].
     pub fn from_paths<P: AsRef<Path>, I: IntoIterator<Item=P>>(paths: I) -> Result<Self> {
+        Self::from_paths_with_options(paths, &LoadOptions::default())
+    }
+
+    /// Load rules from the given paths, honoring `options` for status filtering
+    /// and the open-file budget.
+    pub fn from_paths_with_options<P: AsRef<Path>, I: IntoIterator<Item=P>>(
+        paths: I,
+        options: &LoadOptions,
+    ) -> Result<Self> {
         let mut num_paths = 0;
         let mut rules = Rules::new();
         for input in paths {
             num_paths += 1;
             let input = input.as_ref();
             if input.is_file() {
-                rules.extend(Rules::from_yaml_file(input)?);
+                if archive_kind(input).is_some() {
+                    rules.extend_rules(Rules::from_archive(input)?);
+                } else {
+                    rules.extend_rules(Rules::from_file(input)?);
+                }
             } else if input.is_dir() {
-                rules.extend(Rules::from_directory(input)?);
+                rules.extend_rules(Rules::from_directory_with_options(input, options)?);
             } else {
                 bail!("Unhandled input type: {} is neither a file nor directory", input.display());
             }
         }
+        rules.retain_by_status(options);
         debug!("Loaded {} rules from {num_paths} paths", rules.len());
         Ok(rules)
     }
@@ -55,47 +380,179 @@ impl Rules {
     pub fn from_yaml_file<P: AsRef<Path>>(path: P) -> Result<Self> {
         let path = path.as_ref();
         let _span = debug_span!("Rules::from_yaml_file", "{}", path.display()).entered();
-        let rules: Self = util::load_yaml_file(path)
+        let loaded: Self = util::load_yaml_file(path)
             .with_context(|| format!("Failed to load rules YAML from {}", path.display()))?;
+        let mut rules = Rules::new();
+        rules.extend_from_path(path, loaded.rules);
         debug!("Loaded {} rules from {}", rules.len(), path.display());
         Ok(rules)
     }
 
+    /// Load rules from a single file, dispatching on its extension.
+    ///
+    /// Recognized formats are `.yaml`/`.yml` (YAML), `.json` (JSON), `.toml`
+    /// (TOML), and `.md` (a YAML frontmatter block delimited by `---` with the
+    /// markdown body kept as documentation).
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let _span = debug_span!("Rules::from_file", "{}", path.display()).entered();
+        let contents = std::fs::read(path)
+            .with_context(|| format!("Failed to read rules from {}", path.display()))?;
+        let loaded = deserialize_rules(path, &contents)?;
+        let mut rules = Rules::new();
+        rules.extend_from_path(path, loaded);
+        debug!("Loaded {} rules from {}", rules.len(), path.display());
+        Ok(rules)
+    }
+
+    /// Load rules from a compressed archive (`.tar.gz`/`.tgz`/`.zip`), dispatching
+    /// each contained file by format.
+    pub fn from_archive<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let _span = debug_span!("Rules::from_archive", "{}", path.display()).entered();
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Failed to open rules archive {}", path.display()))?;
+
+        let entries = match archive_kind(path) {
+            Some(ArchiveKind::TarGz) => read_tar_gz(file),
+            Some(ArchiveKind::Zip) => read_zip(file),
+            None => bail!("Unhandled archive type: {}", path.display()),
+        }
+        .with_context(|| format!("Failed to read rules archive {}", path.display()))?;
+
+        let pairs: Vec<(&Path, &[u8])> =
+            entries.iter().map(|(p, b)| (p.as_path(), b.as_slice())).collect();
+        let rules = Rules::from_paths_and_contents(pairs)?;
+        debug!("Loaded {} rules from archive {}", rules.len(), path.display());
+        Ok(rules)
+    }
+
+    /// Load rules from the given files, dispatching each on its extension.
+    pub fn from_files<P: AsRef<Path>, I: IntoIterator<Item=P>>(paths: I) -> Result<Self> {
+        let mut num_paths = 0;
+        let mut rules = Rules::new();
+        for path in paths {
+            num_paths += 1;
+            rules.extend_rules(Rules::from_file(path.as_ref())?);
+        }
+        debug!("Loaded {} rules from {num_paths} files", rules.len());
+        Ok(rules)
+    }
+
     /// Load rules from the given YAML files.
     pub fn from_yaml_files<P: AsRef<Path>, I: IntoIterator<Item=P>>(paths: I) -> Result<Self> {
         let mut num_paths = 0;
-        let mut rules = Vec::new();
+        let mut rules = Rules::new();
         for path in paths {
             num_paths += 1;
-            rules.extend(Rules::from_yaml_file(path.as_ref())?);
+            rules.extend_rules(Rules::from_yaml_file(path.as_ref())?);
         }
         debug!("Loaded {} rules from {num_paths} files", rules.len());
-        Ok(Rules { rules })
+        Ok(rules)
     }
 
-    /// Load rules from YAML files found recursively within the given directory.
+    /// Load rules from rule files found recursively within the given directory.
+    ///
+    /// Discovery and parsing run in parallel with the number of concurrently
+    /// open files bounded by a default budget (see
+    /// [`Rules::from_directory_with_options`]).
     pub fn from_directory<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_directory_with_options(path, &LoadOptions::default())
+    }
+
+    /// Load rules from a directory, bounding the number of rule files open at
+    /// once to `max_open_files` (defaulting to a fraction of the process's open
+    /// descriptor limit when `None`).
+    pub fn from_directory_with_budget<P: AsRef<Path>>(
+        path: P,
+        max_open_files: Option<usize>,
+    ) -> Result<Self> {
+        Self::from_directory_with_options(
+            path,
+            &LoadOptions { max_open_files, ..LoadOptions::default() },
+        )
+    }
+
+    /// Load rules from a directory, honoring `options` for the open-file budget,
+    /// status filtering, include/exclude globs, and a root `.npignore`.
+    pub fn from_directory_with_options<P: AsRef<Path>>(
+        path: P,
+        options: &LoadOptions,
+    ) -> Result<Self> {
         let path = path.as_ref();
         let _span = debug_span!("Rules::from_directory", "{}", path.display()).entered();
 
-        let yaml_types = TypesBuilder::new().add_defaults().select("yaml").build()?;
+        let mut types_builder = TypesBuilder::new();
+        types_builder.add_defaults();
+        for name in RULE_FILE_TYPES {
+            types_builder.select(name);
+        }
+        let types = types_builder.build()?;
 
-        let walker = WalkBuilder::new(path)
-            .types(yaml_types)
+        let mut overrides = OverrideBuilder::new(path);
+        for glob in &options.includes {
+            overrides.add(glob)?;
+        }
+        for glob in &options.excludes {
+            overrides.add(&format!("!{glob}"))?;
+        }
+        let overrides = overrides.build()?;
+
+        // Discover rule files in parallel.
+        let mut builder = WalkBuilder::new(path);
+        builder
+            .types(types)
+            .overrides(overrides)
             .follow_links(true)
-            .standard_filters(false)
-            .build();
-        let mut yaml_files = Vec::new();
-        for entry in walker {
-            let entry = entry?;
-            if entry.file_type().map_or(false, |t| !t.is_dir()) {
-                yaml_files.push(entry.into_path());
-            }
+            .standard_filters(false);
+        // Honor an optional `.npignore` carried in the rules tree.
+        builder.add_custom_ignore_filename(".npignore");
+        let walker = builder.build_parallel();
+
+        let found = Mutex::new(Vec::new());
+        let walk_error = Mutex::new(None);
+        walker.run(|| {
+            let found = &found;
+            let walk_error = &walk_error;
+            Box::new(move |result| {
+                match result {
+                    Ok(entry) => {
+                        let is_file = entry.file_type().map_or(false, |t| !t.is_dir());
+                        let path = entry.into_path();
+                        // The builtin `ignore` type selectors are broader than the
+                        // extensions `deserialize_rules` handles (e.g. `json` also
+                        // matches `*.sarif`, `toml` matches `Cargo.lock`); restrict
+                        // to actual rule files so a stray file can't abort the load.
+                        if is_file && is_rule_file(&path) {
+                            found.lock().unwrap().push(path);
+                        }
+                    }
+                    Err(e) => {
+                        *walk_error.lock().unwrap() = Some(e);
+                        return ignore::WalkState::Quit;
+                    }
+                }
+                ignore::WalkState::Continue
+            })
+        });
+        if let Some(e) = walk_error.into_inner().unwrap() {
+            return Err(e.into());
         }
-        yaml_files.sort();
-        debug!("Found {} rules files to load within {}", yaml_files.len(), path.display());
 
-        Self::from_yaml_files(&yaml_files)
+        let mut rule_files = found.into_inner().unwrap();
+        rule_files.sort();
+        debug!("Found {} rules files to load within {}", rule_files.len(), path.display());
+
+        let budget = options.max_open_files.unwrap_or_else(default_open_file_budget).max(1);
+        let parsed = parse_files_bounded(&rule_files, budget)?;
+
+        // Merge in the (already path-sorted) discovery order for stable ordinals.
+        let mut rules = Rules::new();
+        for sub in parsed {
+            rules.extend_rules(sub);
+        }
+        rules.retain_by_status(options);
+        Ok(rules)
     }
 
     /// How many rules are in this collection?
@@ -110,10 +567,195 @@ impl Rules {
         self.rules.is_empty()
     }
 
+    /// The rules in this collection.
+    #[inline]
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
     #[inline]
     pub fn iter(&self) -> std::slice::Iter<'_, Rule> {
         self.rules.iter()
     }
+
+    /// Iterate over each rule together with the source it was loaded from.
+    #[inline]
+    pub fn iter_with_source(&self) -> impl Iterator<Item = (&RuleSource, &Rule)> {
+        self.sources.iter().zip(self.rules.iter())
+    }
+
+    /// Check this collection for problems, aggregating *all* of them into a
+    /// single [`ValidationReport`] rather than bailing on the first.
+    ///
+    /// The checks are: duplicate rule `id`s across all source files, regexes
+    /// that fail to compile, and structural problems (empty `id`, `name`, or
+    /// `pattern`). Each problem carries the source path/ordinal of the
+    /// offending rule.
+    pub fn validate(&self) -> Result<ValidationReport> {
+        let mut report = ValidationReport::default();
+
+        // Track the first source seen for each id so duplicates can point back
+        // at the original definition.
+        let mut seen_ids: HashMap<&str, &RuleSource> = HashMap::new();
+
+        for (source, rule) in self.iter_with_source() {
+            if rule.id.is_empty() {
+                report.push(source, &rule.id, ValidationProblemKind::EmptyField { field: "id" });
+            }
+            if rule.name.is_empty() {
+                report.push(source, &rule.id, ValidationProblemKind::EmptyField { field: "name" });
+            }
+            if rule.pattern.is_empty() {
+                report.push(source, &rule.id, ValidationProblemKind::EmptyField { field: "pattern" });
+            } else if let Err(e) = Regex::new(&rule.pattern) {
+                report.push(source, &rule.id, ValidationProblemKind::BadRegex { message: e.to_string() });
+            }
+
+            if !rule.id.is_empty() {
+                match seen_ids.get(rule.id.as_str()) {
+                    Some(first) => report.push(
+                        source,
+                        &rule.id,
+                        ValidationProblemKind::DuplicateId { first: (*first).clone() },
+                    ),
+                    None => {
+                        seen_ids.insert(rule.id.as_str(), source);
+                    }
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Add a single rule with the given source, keeping `rules` and `sources` in step.
+    pub fn push(&mut self, source: RuleSource, rule: Rule) {
+        self.rules.push(rule);
+        self.sources.push(source);
+    }
+
+    /// Append `rules` to this collection, recording each one's provenance as an
+    /// ordinal within `path`.
+    fn extend_from_path<I: IntoIterator<Item = Rule>>(&mut self, path: &Path, rules: I) {
+        for (ordinal, rule) in rules.into_iter().enumerate() {
+            self.rules.push(rule);
+            self.sources.push(RuleSource::new(path, ordinal));
+        }
+    }
+
+    /// Drop rules whose [`RuleStatus`] is not enabled by `options`, logging how
+    /// many of each kind were skipped.
+    fn retain_by_status(&mut self, options: &LoadOptions) {
+        let mut skipped_deprecated = 0;
+        let mut skipped_experimental = 0;
+
+        let rules = std::mem::take(&mut self.rules);
+        let sources = std::mem::take(&mut self.sources);
+        for (rule, source) in rules.into_iter().zip(sources) {
+            let keep = match rule.status {
+                RuleStatus::Stable => true,
+                RuleStatus::Experimental => options.include_experimental,
+                RuleStatus::Deprecated => options.include_deprecated,
+            };
+            if keep {
+                self.rules.push(rule);
+                self.sources.push(source);
+            } else {
+                match rule.status {
+                    RuleStatus::Deprecated => skipped_deprecated += 1,
+                    RuleStatus::Experimental => skipped_experimental += 1,
+                    RuleStatus::Stable => {}
+                }
+            }
+        }
+
+        debug!(
+            "Loaded {} rules, skipped {} deprecated, {} experimental",
+            self.len(),
+            skipped_deprecated,
+            skipped_experimental,
+        );
+    }
+
+    /// Merge another collection into this one, preserving its provenance.
+    fn extend_rules(&mut self, other: Rules) {
+        self.rules.extend(other.rules);
+        self.sources.extend(other.sources);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// ValidationReport
+// -------------------------------------------------------------------------------------------------
+/// The aggregated result of [`Rules::validate`]: every problem found while
+/// linting a loaded ruleset.
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    pub problems: Vec<ValidationProblem>,
+}
+
+impl ValidationReport {
+    /// Does this report contain any problems?
+    #[inline]
+    pub fn has_problems(&self) -> bool {
+        !self.problems.is_empty()
+    }
+
+    fn push(&mut self, source: &RuleSource, rule_id: &str, kind: ValidationProblemKind) {
+        self.problems.push(ValidationProblem {
+            source: source.clone(),
+            rule_id: rule_id.to_string(),
+            kind,
+        });
+    }
+}
+
+impl std::fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for problem in &self.problems {
+            writeln!(f, "{problem}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A single problem detected by [`Rules::validate`], tagged with the provenance
+/// of the offending rule.
+#[derive(Debug)]
+pub struct ValidationProblem {
+    pub source: RuleSource,
+    pub rule_id: String,
+    pub kind: ValidationProblemKind,
+}
+
+impl std::fmt::Display for ValidationProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let id = if self.rule_id.is_empty() { "<missing id>" } else { self.rule_id.as_str() };
+        write!(f, "{} (rule {id}): {}", self.source, self.kind)
+    }
+}
+
+/// The kind of a [`ValidationProblem`].
+#[derive(Debug)]
+pub enum ValidationProblemKind {
+    /// A rule reused an `id` already defined elsewhere.
+    DuplicateId { first: RuleSource },
+    /// A rule's `pattern` failed to compile as a regex.
+    BadRegex { message: String },
+    /// A required field was empty.
+    EmptyField { field: &'static str },
+}
+
+impl std::fmt::Display for ValidationProblemKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationProblemKind::DuplicateId { first } => {
+                write!(f, "duplicate id, first defined at {first}")
+            }
+            ValidationProblemKind::BadRegex { message } => write!(f, "invalid regex: {message}"),
+            ValidationProblemKind::EmptyField { field } => write!(f, "missing required field `{field}`"),
+        }
+    }
 }
 
 /// Creates an empty collection of rules.
@@ -123,12 +765,6 @@ impl Default for Rules {
     }
 }
 
-impl Extend<Rule> for Rules {
-    fn extend<T: IntoIterator<Item = Rule>>(&mut self, iter: T) {
-        self.rules.extend(iter);
-    }
-}
-
 impl IntoIterator for Rules {
     type Item = Rule;
     type IntoIter = <Vec<Rule> as IntoIterator>::IntoIter;
@@ -136,3 +772,316 @@ impl IntoIterator for Rules {
         self.rules.into_iter()
     }
 }
+
+/// Bare rules pushed this way get a [`RuleSource::synthetic`] provenance.
+impl Extend<Rule> for Rules {
+    fn extend<T: IntoIterator<Item = Rule>>(&mut self, iter: T) {
+        for rule in iter {
+            self.push(RuleSource::synthetic(), rule);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_frontmatter_basic() {
+        let (fm, body) = split_frontmatter("\nid: x\n---\nbody text\n").unwrap();
+        assert_eq!(fm, "\nid: x\n");
+        assert_eq!(body, "body text\n");
+    }
+
+    #[test]
+    fn split_frontmatter_missing_closing() {
+        assert!(split_frontmatter("\nid: x\nno terminator\n").is_none());
+    }
+
+    #[test]
+    fn split_frontmatter_crlf() {
+        let (fm, body) = split_frontmatter("\r\nid: x\r\n---\r\nbody\r\n").unwrap();
+        assert_eq!(fm, "\r\nid: x\r\n");
+        assert_eq!(body, "body\r\n");
+    }
+
+    #[test]
+    fn markdown_rule_keeps_body_as_reference() {
+        let md = b"---\nname: AWS Key\nid: aws.key\npattern: \"AKIA[0-9A-Z]{16}\"\n---\nSee the AWS docs.\n";
+        let rules = deserialize_markdown_rule(Path::new("aws.md"), md).unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].id, "aws.key");
+        assert_eq!(rules[0].references, vec!["See the AWS docs.".to_string()]);
+    }
+
+    #[test]
+    fn markdown_rule_empty_body_adds_no_reference() {
+        let md = b"---\nname: AWS Key\nid: aws.key\npattern: x\n---\n   \n";
+        let rules = deserialize_markdown_rule(Path::new("aws.md"), md).unwrap();
+        assert!(rules[0].references.is_empty());
+    }
+
+    #[test]
+    fn markdown_rule_unterminated_frontmatter_errors() {
+        let md = b"---\nname: x\nid: y\npattern: z\n";
+        assert!(deserialize_markdown_rule(Path::new("r.md"), md).is_err());
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // Helpers
+    // ---------------------------------------------------------------------------------------------
+    fn rule_with(id: &str, pattern: &str, status: RuleStatus) -> Rule {
+        Rule {
+            name: id.to_string(),
+            id: id.to_string(),
+            pattern: pattern.to_string(),
+            references: Vec::new(),
+            status,
+        }
+    }
+
+    fn rule(id: &str, pattern: &str) -> Rule {
+        rule_with(id, pattern, RuleStatus::Stable)
+    }
+
+    fn rule_yaml(id: &str, pattern: &str) -> String {
+        format!("rules:\n  - name: {id}\n    id: {id}\n    pattern: '{pattern}'\n")
+    }
+
+    /// Create a fresh, empty temporary directory unique to `tag`.
+    fn temp_dir(tag: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("noseyparker-rules-test-{tag}"));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // validate
+    // ---------------------------------------------------------------------------------------------
+    #[test]
+    fn validate_aggregates_all_problems() {
+        let mut rules = Rules::new();
+        rules.push(RuleSource::new("a.yaml", 0), rule("dup", "ok"));
+        rules.push(RuleSource::new("b.yaml", 0), rule("dup", "ok")); // duplicate id
+        rules.push(RuleSource::new("c.yaml", 0), rule("bad", "(")); // bad regex
+        rules.push(RuleSource::new("d.yaml", 0), rule("", "ok")); // empty id
+        rules.push(RuleSource::new("e.yaml", 0), rule("empty", "")); // empty pattern
+
+        let report = rules.validate().unwrap();
+        assert!(report.has_problems());
+
+        let count = |pred: fn(&ValidationProblemKind) -> bool| {
+            report.problems.iter().filter(|p| pred(&p.kind)).count()
+        };
+        assert_eq!(count(|k| matches!(k, ValidationProblemKind::DuplicateId { .. })), 1);
+        assert_eq!(count(|k| matches!(k, ValidationProblemKind::BadRegex { .. })), 1);
+        assert_eq!(
+            count(|k| matches!(k, ValidationProblemKind::EmptyField { field: "id" })),
+            1
+        );
+        assert_eq!(
+            count(|k| matches!(k, ValidationProblemKind::EmptyField { field: "pattern" })),
+            1
+        );
+    }
+
+    #[test]
+    fn validate_clean_ruleset_has_no_problems() {
+        let mut rules = Rules::new();
+        rules.push(RuleSource::new("a.yaml", 0), rule("one", "ok"));
+        rules.push(RuleSource::new("a.yaml", 1), rule("two", "also-ok"));
+        assert!(!rules.validate().unwrap().has_problems());
+    }
+
+    #[test]
+    fn validate_duplicate_points_at_first_source() {
+        let mut rules = Rules::new();
+        rules.push(RuleSource::new("first.yaml", 3), rule("dup", "ok"));
+        rules.push(RuleSource::new("second.yaml", 0), rule("dup", "ok"));
+        let report = rules.validate().unwrap();
+        let dup = report
+            .problems
+            .iter()
+            .find(|p| matches!(p.kind, ValidationProblemKind::DuplicateId { .. }))
+            .unwrap();
+        match &dup.kind {
+            ValidationProblemKind::DuplicateId { first } => {
+                assert_eq!(first, &RuleSource::new("first.yaml", 3));
+            }
+            _ => unreachable!(),
+        }
+        assert_eq!(dup.source, RuleSource::new("second.yaml", 0));
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // retain_by_status
+    // ---------------------------------------------------------------------------------------------
+    fn status_fixture() -> Rules {
+        let mut rules = Rules::new();
+        rules.push(RuleSource::new("r.yaml", 0), rule_with("s", "ok", RuleStatus::Stable));
+        rules.push(RuleSource::new("r.yaml", 1), rule_with("e", "ok", RuleStatus::Experimental));
+        rules.push(RuleSource::new("r.yaml", 2), rule_with("d", "ok", RuleStatus::Deprecated));
+        rules
+    }
+
+    fn ids(rules: &Rules) -> Vec<&str> {
+        rules.iter().map(|r| r.id.as_str()).collect()
+    }
+
+    #[test]
+    fn retain_by_status_default_keeps_only_stable() {
+        let mut rules = status_fixture();
+        rules.retain_by_status(&LoadOptions::default());
+        assert_eq!(ids(&rules), vec!["s"]);
+        assert_eq!(rules.rules().len(), rules.iter_with_source().count());
+    }
+
+    #[test]
+    fn retain_by_status_opts_in_experimental_and_deprecated() {
+        let mut rules = status_fixture();
+        rules.retain_by_status(&LoadOptions {
+            include_experimental: true,
+            ..LoadOptions::default()
+        });
+        assert_eq!(ids(&rules), vec!["s", "e"]);
+
+        let mut rules = status_fixture();
+        rules.retain_by_status(&LoadOptions {
+            include_deprecated: true,
+            include_experimental: true,
+            ..LoadOptions::default()
+        });
+        assert_eq!(ids(&rules), vec!["s", "e", "d"]);
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // archives
+    // ---------------------------------------------------------------------------------------------
+    #[test]
+    fn read_tar_gz_filters_and_sorts() {
+        use std::io::Write;
+
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        ));
+        let append = |builder: &mut tar::Builder<_>, name: &str, data: &[u8]| {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, name, data).unwrap();
+        };
+        append(&mut builder, "b.yaml", rule_yaml("b", "ok").as_bytes());
+        append(&mut builder, "a.yaml", rule_yaml("a", "ok").as_bytes());
+        append(&mut builder, "notes.txt", b"not a rule file");
+        let gz = builder.into_inner().unwrap().finish().unwrap();
+
+        let entries = read_tar_gz(std::io::Cursor::new(gz)).unwrap();
+        let names: Vec<_> = entries.iter().map(|(p, _)| p.to_str().unwrap()).collect();
+        assert_eq!(names, vec!["a.yaml", "b.yaml"]);
+    }
+
+    #[test]
+    fn read_zip_filters_and_sorts() {
+        use std::io::Write;
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = zip::ZipWriter::new(std::io::Cursor::new(&mut buf));
+            let opts = zip::write::SimpleFileOptions::default();
+            for name in ["b.yaml", "Cargo.lock", "a.yaml"] {
+                writer.start_file(name, opts).unwrap();
+                writer.write_all(rule_yaml("x", "ok").as_bytes()).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+
+        let entries = read_zip(std::io::Cursor::new(buf)).unwrap();
+        let names: Vec<_> = entries.iter().map(|(p, _)| p.to_str().unwrap()).collect();
+        assert_eq!(names, vec!["a.yaml", "b.yaml"]);
+    }
+
+    // ---------------------------------------------------------------------------------------------
+    // directory loading: ordinal stability, globs, .npignore
+    // ---------------------------------------------------------------------------------------------
+    #[test]
+    fn from_directory_yields_stable_ordinals_under_concurrency() {
+        let dir = temp_dir("ordinals");
+        std::fs::write(
+            dir.join("01.yaml"),
+            format!("rules:\n  - {{name: x, id: x, pattern: a}}\n  - {{name: y, id: y, pattern: b}}\n"),
+        )
+        .unwrap();
+        std::fs::write(dir.join("02.yaml"), rule_yaml("z", "c")).unwrap();
+
+        // A budget of 1 forces serial parsing; a larger budget parses in
+        // parallel. Both must produce identical, path-sorted results.
+        let serial = Rules::from_directory_with_budget(&dir, Some(1)).unwrap();
+        let parallel = Rules::from_directory_with_budget(&dir, Some(8)).unwrap();
+
+        let provenance = |rules: &Rules| -> Vec<(String, usize, String)> {
+            rules
+                .iter_with_source()
+                .map(|(s, r)| {
+                    (s.path.file_name().unwrap().to_str().unwrap().to_string(), s.ordinal, r.id.clone())
+                })
+                .collect()
+        };
+
+        let expected = vec![
+            ("01.yaml".to_string(), 0, "x".to_string()),
+            ("01.yaml".to_string(), 1, "y".to_string()),
+            ("02.yaml".to_string(), 0, "z".to_string()),
+        ];
+        assert_eq!(provenance(&serial), expected);
+        assert_eq!(provenance(&parallel), expected);
+    }
+
+    #[test]
+    fn from_directory_honors_include_and_exclude_globs() {
+        let dir = temp_dir("globs");
+        for id in ["aws", "gcp", "azure"] {
+            std::fs::write(dir.join(format!("{id}.yaml")), rule_yaml(id, "ok")).unwrap();
+        }
+
+        let included = Rules::from_directory_with_options(
+            &dir,
+            &LoadOptions { includes: vec!["aws.yaml".to_string()], ..LoadOptions::default() },
+        )
+        .unwrap();
+        assert_eq!(ids(&included), vec!["aws"]);
+
+        let excluded = Rules::from_directory_with_options(
+            &dir,
+            &LoadOptions { excludes: vec!["gcp.yaml".to_string()], ..LoadOptions::default() },
+        )
+        .unwrap();
+        assert_eq!(ids(&excluded), vec!["aws", "azure"]);
+    }
+
+    #[test]
+    fn from_directory_honors_npignore() {
+        let dir = temp_dir("npignore");
+        for id in ["aws", "gcp"] {
+            std::fs::write(dir.join(format!("{id}.yaml")), rule_yaml(id, "ok")).unwrap();
+        }
+        std::fs::write(dir.join(".npignore"), "gcp.yaml\n").unwrap();
+
+        let rules = Rules::from_directory(&dir).unwrap();
+        assert_eq!(ids(&rules), vec!["aws"]);
+    }
+
+    #[test]
+    fn from_directory_skips_non_rule_files() {
+        let dir = temp_dir("non-rule");
+        std::fs::write(dir.join("aws.yaml"), rule_yaml("aws", "ok")).unwrap();
+        // Files the builtin type selectors match but `deserialize_rules` can't.
+        std::fs::write(dir.join("Cargo.lock"), "name = \"x\"\n").unwrap();
+        std::fs::write(dir.join("sample.sarif"), "{}\n").unwrap();
+
+        let rules = Rules::from_directory(&dir).unwrap();
+        assert_eq!(ids(&rules), vec!["aws"]);
+    }
+}